@@ -30,7 +30,7 @@
 //!     .type_error_message("Error: What kind of age is that?!")
 //!     .get();
 //! ```
-//! 
+//!
 //! [`.validator_error_message()`] can be used to specify an error message when your matches condition does not hold.
 //!
 //! ```no_run
@@ -50,7 +50,7 @@
 //!     panic!("Aborted!");
 //! }
 //! ```
-//! 
+//!
 //! //! A convenience function [`confirm_with_message`] is provided for getting a yes or no
 //! answer with an error message.
 //!
@@ -62,14 +62,80 @@
 //! }
 //! ```
 //!
+//! [`.password()`] hides the input while typing, for secrets such as
+//! passwords. [`.confirm_password()`] additionally asks twice and only
+//! returns once both entries match.
+//!
+//! ```no_run
+//! let password: String = informal::prompt("Password: ").password().get();
+//! ```
+//!
+//! A convenience function [`confirm_challenge`] is provided for dangerous
+//! operations that shouldn't be confirmed by reflex: it makes the user
+//! solve a small arithmetic problem before returning `true`.
+//!
+//! ```no_run
+//! if informal::confirm_challenge("This will delete everything.") {
+//!     // continue
+//! }
+//! ```
+//!
+//! [`select`] (or [`.choices()`] on `Input` directly) prompts the user to
+//! pick from a fixed, numbered list of choices instead of parsing free-form
+//! input.
+//!
+//! ```no_run
+//! let fruit: String = informal::select(
+//!     "Pick a fruit: ",
+//!     vec!["apple".to_string(), "pear".to_string()],
+//! )
+//! .get();
+//! ```
+//!
+//! [`.get_opt()`] returns `None` instead of panicking when the user gives
+//! up, and [`.max_attempts()`] bounds how many times a bad answer is
+//! re-prompted before giving up.
+//!
+//! ```no_run
+//! let age: Option<u32> = informal::prompt("Enter your age: ")
+//!     .max_attempts(3)
+//!     .get_opt();
+//! ```
+//!
+//! [`.theme()`] sets the [`Theme`] used to render the prompt and its error
+//! messages; [`ColoredTheme`] adds a bold `[?]` marker and red error lines,
+//! falling back to plain text when stdout isn't a terminal.
+//!
+//! ```no_run
+//! # use informal::ColoredTheme;
+//! let age: u32 = informal::prompt("Enter your age: ")
+//!     .theme(ColoredTheme)
+//!     .get();
+//! ```
+//!
 //! [`FromStr`]: https://doc.rust-lang.org/std/str/trait.FromStr.html
 //! [`.matches()`]: struct.Input.html#method.matches
 //! [`confirm`]: fn.confirm.html
+//! [`.password()`]: struct.Input.html#method.password
+//! [`.confirm_password()`]: struct.Input.html#method.confirm_password
+//! [`confirm_challenge`]: fn.confirm_challenge.html
+//! [`select`]: fn.select.html
+//! [`.choices()`]: struct.Input.html#method.choices
+//! [`.get_opt()`]: struct.Input.html#method.get_opt
+//! [`.max_attempts()`]: struct.Input.html#method.max_attempts
+//! [`.theme()`]: struct.Input.html#method.theme
+//! [`Theme`]: trait.Theme.html
+//! [`ColoredTheme`]: struct.ColoredTheme.html
 
+use std::cell::RefCell;
 use std::fmt::{self, Debug, Display};
-use std::io::{self, Write};
+use std::io::{self, IsTerminal};
 use std::str::FromStr;
 
+use rpassword::prompt_password;
+use rustyline::error::ReadlineError;
+use rustyline::DefaultEditor;
+
 /////////////////////////////////////////////////////////////////////////
 // Definitions
 /////////////////////////////////////////////////////////////////////////
@@ -79,6 +145,87 @@ struct Validator<T> {
     raw: Box<dyn Fn(&T) -> bool + 'static>,
 }
 
+/// A small problem the user must solve before a prompt is accepted, so that
+/// dangerous operations can't be confirmed by reflex.
+#[derive(Clone)]
+pub enum Challenge {
+    /// Solve `(a + b) mod m` for small, freshly picked `a`, `b`, and `m`.
+    Arithmetic,
+    /// Type the given sentence back, verbatim.
+    Phrase(String),
+}
+
+/// Controls how a prompt and its error messages are rendered.
+///
+/// Implement this to customize formatting (e.g. colors); see [`PlainTheme`]
+/// and [`ColoredTheme`] for the two themes shipped with this crate.
+pub trait Theme {
+    /// Format the assembled prompt (prefix + prompt + suffix) shown before
+    /// reading a line.
+    fn prompt(&self, prompt: &str) -> String;
+
+    /// Format a type-conversion or validator error line.
+    fn error(&self, message: &str) -> String;
+}
+
+/// The default theme: prompts and error messages are shown as plain text,
+/// with no escape codes.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PlainTheme;
+
+impl Theme for PlainTheme {
+    fn prompt(&self, prompt: &str) -> String {
+        prompt.to_string()
+    }
+
+    fn error(&self, message: &str) -> String {
+        message.to_string()
+    }
+}
+
+/// A colored theme in the vein of `dialoguer`'s `ColorfulTheme`: a bold
+/// `[?]` marker before the prompt and red error lines.
+///
+/// Falls back to [`PlainTheme`]'s plain output when stdout is not a
+/// terminal (e.g. when piped to a file or another process).
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ColoredTheme;
+
+impl Theme for ColoredTheme {
+    fn prompt(&self, prompt: &str) -> String {
+        if io::stdout().is_terminal() {
+            format!("\x1b[1m[?]\x1b[0m {}", prompt)
+        } else {
+            prompt.to_string()
+        }
+    }
+
+    fn error(&self, message: &str) -> String {
+        if io::stdout().is_terminal() {
+            format!("\x1b[31m{}\x1b[0m", message)
+        } else {
+            message.to_string()
+        }
+    }
+}
+
+/// Resolves a raw line of input into one of the choices passed to
+/// `.choices()`.
+type ChoiceResolver<T> = Box<dyn Fn(&str) -> Option<T>>;
+
+/// Renders the numbered choice list, given the current default (if any) so
+/// it can mark which entry that is.
+type ChoiceRenderer<T> = Box<dyn Fn(Option<&T>) -> Vec<String>>;
+
+/// A fixed set of choices built by [`Input::choices`], along with the
+/// display-string-dependent logic it needs. Keeping that logic behind these
+/// boxed closures means `Input<T>`'s base API (`get`, `try_get`, ...) only
+/// ever needs `T: FromStr`, even when choices are in play.
+struct ChoiceSet<T> {
+    resolve: ChoiceResolver<T>,
+    render: ChoiceRenderer<T>,
+}
+
 /// An input builder.
 pub struct Input<T> {
     prompt: Option<String>,
@@ -88,6 +235,14 @@ pub struct Input<T> {
     validator: Option<Validator<T>>,
     type_message: Option<String>,
     validator_message: Option<String>,
+    password: bool,
+    confirm_password: bool,
+    history: bool,
+    challenge: Option<Challenge>,
+    challenge_attempts: usize,
+    items: Option<ChoiceSet<T>>,
+    max_attempts: Option<usize>,
+    theme: Box<dyn Theme>,
 }
 
 /////////////////////////////////////////////////////////////////////////
@@ -142,6 +297,14 @@ impl<T> Input<T> {
             validator: None,
             type_message: Some(String::from("Error: invalid input")),
             validator_message: Some(String::from("Error: does not pass validation")),
+            password: false,
+            confirm_password: false,
+            history: true,
+            challenge: None,
+            challenge_attempts: 3,
+            items: None,
+            max_attempts: None,
+            theme: Box::new(PlainTheme),
         }
     }
 
@@ -184,6 +347,20 @@ impl<T> Input<T> {
         self
     }
 
+    /// Give up and return `None` (via [`get_opt`](Self::get_opt) or
+    /// [`try_get`](Self::try_get)) after this many failed attempts, instead
+    /// of re-prompting forever.
+    pub fn max_attempts(mut self, attempts: usize) -> Self {
+        self.max_attempts = Some(attempts);
+        self
+    }
+
+    /// Set the [`Theme`] used to render the prompt and error messages.
+    pub fn theme(mut self, theme: impl Theme + 'static) -> Self {
+        self.theme = Box::new(theme);
+        self
+    }
+
     /// Check input values.
     ///
     /// If set, this function will be called on the parsed user input and only
@@ -202,17 +379,181 @@ impl<T> Input<T> {
         self.validator = Some(Validator::new(matches));
         self
     }
+
+    /// Read the input without echoing it to the terminal, for secrets such
+    /// as passwords.
+    ///
+    /// Falls back to a plain, visible read when stdin is not a TTY (e.g.
+    /// when piped from a file or another process).
+    pub fn password(mut self) -> Self {
+        self.password = true;
+        self
+    }
+
+    /// Like [`password`](Self::password), but asks twice and only returns
+    /// once both entries match.
+    pub fn confirm_password(mut self) -> Self {
+        self.password = true;
+        self.confirm_password = true;
+        self
+    }
+
+    /// Toggle whether successfully entered values are added to the shared,
+    /// in-session history so they can be recalled with the up arrow.
+    ///
+    /// Enabled by default.
+    pub fn history(mut self, enabled: bool) -> Self {
+        self.history = enabled;
+        self
+    }
+
+    /// Require the user to additionally solve a [`Challenge`] before this
+    /// input is accepted, so that entering a dangerous value can't happen
+    /// by reflex.
+    pub fn challenge(mut self, challenge: Challenge) -> Self {
+        self.challenge = Some(challenge);
+        self
+    }
+
+    /// Set how many attempts the user gets to solve the
+    /// [`.challenge()`](Self::challenge) before the value is rejected.
+    ///
+    /// Defaults to 3.
+    pub fn challenge_attempts(mut self, attempts: usize) -> Self {
+        self.challenge_attempts = attempts;
+        self
+    }
+}
+
+thread_local! {
+    // Shared across every prompt on the current thread so that values
+    // entered earlier can be recalled from a later, unrelated prompt.
+    static EDITOR: RefCell<DefaultEditor> =
+        RefCell::new(DefaultEditor::new().expect("failed to initialise line editor"));
 }
 
 fn read_line(prompt: &Option<String>) -> io::Result<String> {
-    if let Some(prompt) = prompt {
-        let mut stdout = io::stdout();
-        stdout.write_all(prompt.as_bytes())?;
-        stdout.flush()?;
-    }
-    let mut result = String::new();
-    io::stdin().read_line(&mut result)?;
-    Ok(result)
+    let prompt = prompt.as_deref().unwrap_or("");
+    EDITOR.with(|editor| match editor.borrow_mut().readline(prompt) {
+        Ok(line) => Ok(line),
+        Err(ReadlineError::Eof) => Err(io::Error::new(
+            io::ErrorKind::UnexpectedEof,
+            "prompt cancelled (EOF)",
+        )),
+        Err(ReadlineError::Interrupted) => Err(io::Error::new(
+            io::ErrorKind::Interrupted,
+            "prompt cancelled (interrupt)",
+        )),
+        Err(err) => Err(io::Error::other(err.to_string())),
+    })
+}
+
+/// Adds a successfully entered value to the shared history so it can later
+/// be recalled with the up arrow.
+fn record_history(line: &str) {
+    EDITOR.with(|editor| {
+        let _ = editor.borrow_mut().add_history_entry(line);
+    });
+}
+
+/// Reads a line with terminal echo suppressed, optionally asking twice and
+/// requiring the two entries to match.
+///
+/// Falls back to a plain, visible read via [`read_line`] when stdin is not
+/// a TTY (e.g. piped from a file or another process), since there is no
+/// terminal to suppress echo on in the first place.
+///
+/// Returns `Ok(None)` when `confirm` is set and the two entries disagree, so
+/// the caller can re-prompt from scratch.
+fn read_password_line(
+    prompt: &Option<String>,
+    confirm: bool,
+    theme: &dyn Theme,
+) -> io::Result<Option<String>> {
+    let confirm_prompt = theme.prompt("Confirm password: ");
+
+    if !io::stdin().is_terminal() {
+        let first = read_line(prompt)?;
+        if confirm {
+            let second = read_line(&Some(confirm_prompt))?;
+            if first != second {
+                return Ok(None);
+            }
+        }
+        return Ok(Some(first));
+    }
+
+    let first = prompt_password(prompt.as_deref().unwrap_or(""))?;
+    if confirm {
+        let second = prompt_password(&confirm_prompt)?;
+        if first != second {
+            return Ok(None);
+        }
+    }
+    Ok(Some(first))
+}
+
+/// Counts a failed attempt, returning `true` once `max_attempts` (if any)
+/// has been reached and the caller should give up instead of re-prompting.
+fn attempt_exhausted(attempts: &mut usize, max_attempts: Option<usize>) -> bool {
+    *attempts += 1;
+    matches!(max_attempts, Some(max) if *attempts >= max)
+}
+
+/// Resolves a raw line against a fixed set of choices. An exact match
+/// against an item's displayed text always wins; only when the raw line
+/// doesn't match any item's text is it tried as a 1-based index, so a list
+/// whose display text happens to look like an index (e.g. `.choices(vec![5,
+/// 1, 9])`) is still selectable by typing that text.
+fn resolve_choice<T: Display + Clone>(raw: &str, items: &[T]) -> Option<T> {
+    items
+        .iter()
+        .find(|item| item.to_string() == raw)
+        .or_else(|| {
+            raw.parse::<usize>()
+                .ok()
+                .and_then(|idx| idx.checked_sub(1))
+                .and_then(|idx| items.get(idx))
+        })
+        .cloned()
+}
+
+impl<T: Display + Clone + 'static> Input<T> {
+    /// Restrict the accepted input to one of a fixed set of choices,
+    /// rendered as a numbered list. Accepts either an exact match against an
+    /// item's displayed text or, failing that, the 1-based index.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use informal::Input;
+    /// let fruit: String = Input::new()
+    ///     .prompt("Pick a fruit: ")
+    ///     .choices(vec!["apple".to_string(), "pear".to_string()])
+    ///     .get();
+    /// ```
+    pub fn choices(mut self, items: Vec<T>) -> Self {
+        let for_resolve = items.clone();
+        let resolve = move |raw: &str| resolve_choice(raw, &for_resolve);
+        let render = move |default: Option<&T>| -> Vec<String> {
+            items
+                .iter()
+                .enumerate()
+                .map(|(idx, item)| {
+                    let marker = match default {
+                        Some(default) if default.to_string() == item.to_string() => " (default)",
+                        _ => "",
+                    };
+                    format!("  {}) {}{}", idx + 1, item, marker)
+                })
+                .collect()
+        };
+        self.items = Some(ChoiceSet {
+            resolve: Box::new(resolve),
+            render: Box::new(render),
+        });
+        self
+    }
 }
 
 impl<T> Input<T>
@@ -220,7 +561,7 @@ where
     T: FromStr,
     <T as FromStr>::Err: Display,
 {
-    fn try_get_with<F>(self, read_line: F) -> io::Result<T>
+    fn try_get_with<F>(self, read_line: F) -> io::Result<Option<T>>
     where
         F: Fn(&Option<String>) -> io::Result<String>,
     {
@@ -228,12 +569,22 @@ where
             prompt,
             prefix,
             suffix,
-            default,
+            mut default,
             validator,
             type_message: error_message,
             validator_message,
+            password,
+            confirm_password,
+            history,
+            challenge,
+            challenge_attempts,
+            items,
+            max_attempts,
+            theme,
         } = self;
 
+        let mut attempts = 0;
+
         let prompt = prompt.map(move |prompt| {
             let mut p = String::new();
             if let Some(prefix) = prefix {
@@ -245,45 +596,112 @@ where
             }
             p
         });
+        let prompt = prompt.map(|p| theme.prompt(&p));
+
+        if let Some(choice_set) = &items {
+            for line in (choice_set.render)(default.as_ref()) {
+                println!("{}", line);
+            }
+        }
 
         Ok(loop {
-            match read_line(&prompt)?.trim() {
-                "" => {
-                    if let Some(default) = default {
-                        break default;
-                    } else {
+            let line = if password {
+                match read_password_line(&prompt, confirm_password, theme.as_ref())? {
+                    Some(line) => line,
+                    None => {
+                        println!(
+                            "{}",
+                            theme.error("Error: passwords do not match, please try again")
+                        );
+                        if attempt_exhausted(&mut attempts, max_attempts) {
+                            break None;
+                        }
                         continue;
                     }
                 }
-                raw => match raw.parse() {
-                    Ok(result) => {
-                        if let Some(validator) = &validator {
-                            if !validator.run(&result) {
-                                println!(
-                                    "{}",
-                                    validator_message.as_ref().unwrap_or(&"".to_string())
-                                );
-                                continue;
-                            }
-                        }
-                        break result;
+            } else {
+                read_line(&prompt)?
+            };
+            // Passwords must keep any leading/trailing whitespace the user
+            // typed, so only non-password input is trimmed before matching.
+            let line_to_match: &str = if password { &line } else { line.trim() };
+            match line_to_match {
+                "" => {
+                    if let Some(value) = default.take() {
+                        break Some(value);
                     }
-                    Err(err) => {
-                        println!(
-                            "{}",
+                    // No default to fall back on: this only ends the prompt
+                    // once max_attempts (if any) is exhausted, so callers of
+                    // the original, infinite-retry get() keep working.
+                    if attempt_exhausted(&mut attempts, max_attempts) {
+                        break None;
+                    }
+                    continue;
+                }
+                raw => {
+                    let parsed: Result<T, String> = if let Some(choice_set) = &items {
+                        (choice_set.resolve)(raw)
+                            .ok_or_else(|| validator_message.clone().unwrap_or_default())
+                    } else {
+                        raw.parse().map_err(|err| {
                             error_message
-                                .as_ref()
-                                .unwrap_or(&format!("Error: {}", err).to_string())
-                        );
-                        continue;
+                                .clone()
+                                .unwrap_or_else(|| format!("Error: {}", err))
+                        })
+                    };
+                    match parsed {
+                        Ok(result) => {
+                            if let Some(validator) = &validator {
+                                if !validator.run(&result) {
+                                    println!(
+                                        "{}",
+                                        theme.error(validator_message.as_deref().unwrap_or(""))
+                                    );
+                                    if attempt_exhausted(&mut attempts, max_attempts) {
+                                        break None;
+                                    }
+                                    continue;
+                                }
+                            }
+                            if let Some(challenge) = &challenge {
+                                if !confirm_challenge_with_theme(
+                                    "Confirm this value:",
+                                    challenge.clone(),
+                                    challenge_attempts,
+                                    theme.as_ref(),
+                                ) {
+                                    if attempt_exhausted(&mut attempts, max_attempts) {
+                                        break None;
+                                    }
+                                    continue;
+                                }
+                            }
+                            if history && !password {
+                                record_history(raw);
+                            }
+                            break Some(result);
+                        }
+                        Err(message) => {
+                            println!("{}", theme.error(&message));
+                            if attempt_exhausted(&mut attempts, max_attempts) {
+                                break None;
+                            }
+                            continue;
+                        }
                     }
-                },
+                }
             }
         })
     }
 
-    #[inline]
-    fn try_get(self) -> io::Result<T> {
+    /// Reads the input from the user without panicking.
+    ///
+    /// Returns `Err` for an I/O failure or a cancelled prompt (Ctrl-C /
+    /// Ctrl-D). An empty input with no default set counts as a failed
+    /// attempt and is re-prompted, not returned as `Ok(None)`; `Ok(None)` is
+    /// returned only once [`.max_attempts()`](Self::max_attempts) is
+    /// exhausted, so without it this re-prompts until a value is accepted.
+    pub fn try_get(self) -> io::Result<Option<T>> {
         self.try_get_with(read_line)
     }
 
@@ -298,7 +716,25 @@ where
     ///
     /// [`FromStr`]: https://doc.rust-lang.org/std/str/trait.FromStr.html
     pub fn get(self) -> T {
-        self.try_get().unwrap()
+        self.try_get()
+            .unwrap()
+            .expect("prompt cancelled or no value entered")
+    }
+
+    /// Like [`get`](Self::get), but returns `None` instead of panicking when
+    /// the user gives up: a cancelled prompt, an I/O error, or an exhausted
+    /// [`.max_attempts()`](Self::max_attempts). An empty input with no
+    /// default is re-prompted as a failed attempt, not returned as `None`.
+    ///
+    /// This lets scripts treat "the user gave up" or a closed pipe as a
+    /// normal, recoverable outcome instead of a panic.
+    ///
+    /// ```no_run
+    /// # use informal::Input;
+    /// let age: Option<u32> = Input::new().prompt("Enter your age: ").get_opt();
+    /// ```
+    pub fn get_opt(self) -> Option<T> {
+        self.try_get().unwrap_or(None)
     }
 
     /// Consumes the `Input` and applies the given function to it.
@@ -366,6 +802,27 @@ where
     Input::new().prompt(text)
 }
 
+/// Returns an `Input` restricted to one of the given choices, rendered as a
+/// numbered list under the given prompt.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use informal::select;
+/// let fruit: String = select(
+///     "Pick a fruit: ",
+///     vec!["apple".to_string(), "pear".to_string()],
+/// )
+/// .get();
+/// ```
+pub fn select<S, T>(text: S, items: Vec<T>) -> Input<T>
+where
+    S: Into<String>,
+    T: Display + Clone + 'static,
+{
+    Input::new().prompt(text).choices(items)
+}
+
 /// Prompts the user for confirmation (yes/no).
 ///
 /// # Examples
@@ -406,3 +863,178 @@ pub fn confirm_with_message<S: Into<String>>(text: S, error_meesage: S) -> bool
         .matches(|s| matches!(&*s.trim().to_lowercase(), "n" | "no" | "y" | "yes"))
         .map(|s| matches!(&*s.to_lowercase(), "y" | "yes"))
 }
+
+/// Picks three small numbers from the current time, without pulling in a
+/// random number generator dependency for it.
+fn small_random_triple() -> (u32, u32, u32) {
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .subsec_nanos();
+    let a = 1 + nanos % 9;
+    let b = 1 + (nanos / 9) % 9;
+    let m = 4 + (nanos / 81) % 4;
+    (a, b, m)
+}
+
+/// Builds the question text and the [`Validator`] that checks a raw answer
+/// against the given [`Challenge`].
+fn validator_for(challenge: &Challenge) -> (String, Validator<String>) {
+    match challenge {
+        Challenge::Arithmetic => {
+            let (a, b, m) = small_random_triple();
+            let expected = (a + b) % m;
+            let question = format!("Solve: ({} + {}) mod {} = ", a, b, m);
+            let validator = Validator::new(move |answer: &String| {
+                answer.trim().parse::<u32>().is_ok_and(|n| n == expected)
+            });
+            (question, validator)
+        }
+        Challenge::Phrase(phrase) => {
+            let expected = phrase.clone();
+            let question = format!("Type the following phrase exactly: \"{}\"\n", phrase);
+            let validator = Validator::new(move |answer: &String| answer == &expected);
+            (question, validator)
+        }
+    }
+}
+
+/// Forces the user to solve a [`Challenge`] before returning `true`, so that
+/// dangerous commands aren't confirmed by reflex. Re-prompts on a wrong or
+/// unparseable answer, giving up and returning `false` after `attempts`
+/// tries (or if the prompt is cancelled).
+///
+/// # Examples
+///
+/// ```no_run
+/// # use informal::{confirm_challenge_with, Challenge};
+/// if confirm_challenge_with("This will delete everything.", Challenge::Arithmetic, 3) {
+///     // continue
+/// }
+/// ```
+pub fn confirm_challenge_with<S: Into<String>>(
+    text: S,
+    challenge: Challenge,
+    attempts: usize,
+) -> bool {
+    confirm_challenge_with_theme(text, challenge, attempts, &PlainTheme)
+}
+
+/// Like [`confirm_challenge_with`], but renders the prompt and the
+/// wrong-answer message through the given [`Theme`] instead of always
+/// plain text. Used internally so [`Input::challenge`] can share its
+/// [`.theme()`](Input::theme) with the challenge it triggers.
+fn confirm_challenge_with_theme<S: Into<String>>(
+    text: S,
+    challenge: Challenge,
+    attempts: usize,
+    theme: &dyn Theme,
+) -> bool {
+    println!("{}", theme.prompt(&text.into()));
+    let (question, validator) = validator_for(&challenge);
+    let question = theme.prompt(&question);
+    for _ in 0..attempts.max(1) {
+        let answer: String = match prompt(question.clone()).try_get() {
+            Ok(Some(answer)) => answer,
+            Ok(None) | Err(_) => return false,
+        };
+        if validator.run(&answer) {
+            return true;
+        }
+        println!("{}", theme.error("Error: that's not correct, try again"));
+    }
+    false
+}
+
+/// Like [`confirm_challenge_with`], using an arithmetic challenge and up to
+/// three attempts.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use informal::confirm_challenge;
+/// if confirm_challenge("This will delete everything.") {
+///     // continue
+/// }
+/// ```
+pub fn confirm_challenge<S: Into<String>>(text: S) -> bool {
+    confirm_challenge_with(text, Challenge::Arithmetic, 3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_choice_prefers_exact_text_over_index() {
+        let items = vec![5, 1, 9];
+        // "1" is both the display text of `items[1]` and a valid 1-based
+        // index into `items[0]`; the exact text match must win.
+        assert_eq!(resolve_choice("1", &items), Some(1));
+        assert_eq!(resolve_choice("2", &items), Some(1));
+        assert_eq!(resolve_choice("9", &items), Some(9));
+        assert_eq!(resolve_choice("nope", &items), None);
+    }
+
+    #[test]
+    fn arithmetic_validator_accepts_only_the_expected_sum() {
+        let (question, validator) = validator_for(&Challenge::Arithmetic);
+        let nums: Vec<u32> = question
+            .split(|c: char| !c.is_ascii_digit())
+            .filter_map(|s| s.parse().ok())
+            .collect();
+        let (a, b, m) = (nums[0], nums[1], nums[2]);
+        let expected = (a + b) % m;
+        assert!(validator.run(&expected.to_string()));
+        assert!(!validator.run(&((expected + 1) % m).to_string()));
+        assert!(!validator.run(&"not a number".to_string()));
+    }
+
+    #[test]
+    fn phrase_validator_requires_an_exact_match() {
+        let (question, validator) = validator_for(&Challenge::Phrase("open sesame".to_string()));
+        assert!(question.contains("open sesame"));
+        assert!(validator.run(&"open sesame".to_string()));
+        assert!(!validator.run(&"Open Sesame".to_string()));
+        assert!(!validator.run(&"open sesam".to_string()));
+    }
+
+    #[test]
+    fn attempt_exhausted_counts_up_to_max_attempts() {
+        let mut attempts = 0;
+        assert!(!attempt_exhausted(&mut attempts, Some(3)));
+        assert!(!attempt_exhausted(&mut attempts, Some(3)));
+        assert!(attempt_exhausted(&mut attempts, Some(3)));
+        assert_eq!(attempts, 3);
+    }
+
+    #[test]
+    fn attempt_exhausted_never_gives_up_without_a_limit() {
+        let mut attempts = 0;
+        for _ in 0..100 {
+            assert!(!attempt_exhausted(&mut attempts, None));
+        }
+    }
+
+    #[test]
+    fn plain_theme_passes_text_through_unchanged() {
+        let theme = PlainTheme;
+        assert_eq!(theme.prompt("Name: "), "Name: ");
+        assert_eq!(theme.error("Error: invalid input"), "Error: invalid input");
+    }
+
+    #[test]
+    fn colored_theme_falls_back_to_plain_text_off_a_terminal() {
+        // The test harness captures output without redirecting stdout itself,
+        // so stdout may or may not be a terminal here. Off a terminal,
+        // ColoredTheme must degrade to exactly PlainTheme's output.
+        let theme = ColoredTheme;
+        if io::stdout().is_terminal() {
+            assert_eq!(theme.prompt("Name: "), "\x1b[1m[?]\x1b[0m Name: ");
+            assert_eq!(theme.error("Error: invalid input"), "\x1b[31mError: invalid input\x1b[0m");
+        } else {
+            assert_eq!(theme.prompt("Name: "), "Name: ");
+            assert_eq!(theme.error("Error: invalid input"), "Error: invalid input");
+        }
+    }
+}